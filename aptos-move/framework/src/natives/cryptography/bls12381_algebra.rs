@@ -0,0 +1,244 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    natives::{
+        cryptography::{bls12381_algebra_pairing, bls12381_algebra_point},
+        helpers::make_safe_native,
+    },
+};
+use aptos_types::on_chain_config::{Features, TimedFeatures};
+use move_core_types::gas_algebra::{
+    GasQuantity, InternalGasPerArg, InternalGasPerByte, InternalGasUnit, NumArgs,
+};
+use move_vm_runtime::native_functions::NativeFunction;
+use std::sync::Arc;
+
+/// The size of a compressed, serialized G1 point, in bytes.
+pub(crate) const G1_COMPRESSED_NUM_BYTES: usize = 48;
+
+/// The size of a compressed, serialized G2 point, in bytes.
+pub(crate) const G2_COMPRESSED_NUM_BYTES: usize = 96;
+
+/// The size of a serialized scalar in the BLS12-381 scalar field Fr, in bytes.
+pub(crate) const FR_NUM_BYTES: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub g1_proj_add: InternalGasPerArg,
+    pub g1_proj_neg: InternalGasPerArg,
+    pub g1_proj_scalar_mul: InternalGasPerArg,
+    pub g1_proj_to_affine: InternalGasPerArg,
+    pub g1_affine_deserialize_compressed: InternalGasPerArg,
+    pub g1_affine_serialize_compressed: InternalGasPerArg,
+    pub g1_affine_subgroup_check: InternalGasPerArg,
+    pub g1_affine_hash_to_curve: InternalGasPerArg,
+    pub g1_affine_hash_to_curve_per_byte: InternalGasPerByte,
+
+    pub g2_proj_add: InternalGasPerArg,
+    pub g2_proj_neg: InternalGasPerArg,
+    pub g2_proj_scalar_mul: InternalGasPerArg,
+    pub g2_proj_to_affine: InternalGasPerArg,
+    pub g2_affine_deserialize_compressed: InternalGasPerArg,
+    pub g2_affine_serialize_compressed: InternalGasPerArg,
+    pub g2_affine_subgroup_check: InternalGasPerArg,
+    pub g2_affine_hash_to_curve: InternalGasPerArg,
+    pub g2_affine_hash_to_curve_per_byte: InternalGasPerByte,
+
+    pub pairing_product_base: InternalGasPerArg,
+    pub pairing_product_per_pair: InternalGasPerArg,
+}
+
+impl GasParameters {
+    /// Returns gas costs for a variable-time multi-scalar multiplication (mulexp) of size `n` in
+    /// G1. Mirrors the estimate used for ristretto255's MSM: Pippenger's algorithm costs roughly
+    /// `O(n / log_2 n)` scalar multiplications.
+    ///
+    /// `size <= 1` is special-cased: `log2(1) == 0` would otherwise divide by zero and saturate
+    /// the cast to `u64::MAX`, charging an unpayable amount of gas for the single-pair case.
+    pub fn g1_multi_scalar_mul_gas(&self, size: usize) -> GasQuantity<InternalGasUnit> {
+        if size <= 1 {
+            return self.g1_proj_scalar_mul * NumArgs::one();
+        }
+        self.g1_proj_scalar_mul * NumArgs::new((size as f64 / f64::log2(size as f64)).ceil() as u64)
+    }
+
+    /// Same as [`Self::g1_multi_scalar_mul_gas`], but for a multi-scalar multiplication in G2.
+    pub fn g2_multi_scalar_mul_gas(&self, size: usize) -> GasQuantity<InternalGasUnit> {
+        if size <= 1 {
+            return self.g2_proj_scalar_mul * NumArgs::one();
+        }
+        self.g2_proj_scalar_mul * NumArgs::new((size as f64 / f64::log2(size as f64)).ceil() as u64)
+    }
+
+    /// Returns gas costs for a multi-pairing check over `n` (G1, G2) pairs.
+    pub fn pairing_product_gas(&self, size: usize) -> GasQuantity<InternalGasUnit> {
+        self.pairing_product_base + self.pairing_product_per_pair * NumArgs::new(size as u64)
+    }
+}
+
+pub fn make_all(
+    gas_params: GasParameters,
+    timed_features: TimedFeatures,
+    features: Arc<Features>,
+) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = vec![];
+
+    natives.append(&mut vec![
+        (
+            "g1_proj_add_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_proj_add,
+            ),
+        ),
+        (
+            "g1_proj_neg_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_proj_neg,
+            ),
+        ),
+        (
+            "g1_proj_scalar_mul_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_proj_scalar_mul,
+            ),
+        ),
+        (
+            "g1_multi_scalar_mul_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_multi_scalar_mul,
+            ),
+        ),
+        (
+            "g1_affine_subgroup_check_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_affine_subgroup_check,
+            ),
+        ),
+        (
+            "g1_affine_serialize_compressed_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_affine_serialize_compressed,
+            ),
+        ),
+        (
+            "g1_affine_deserialize_compressed_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_affine_deserialize_compressed,
+            ),
+        ),
+        (
+            "g1_affine_hash_to_curve_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g1_affine_hash_to_curve,
+            ),
+        ),
+        (
+            "g2_proj_add_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_proj_add,
+            ),
+        ),
+        (
+            "g2_proj_neg_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_proj_neg,
+            ),
+        ),
+        (
+            "g2_proj_scalar_mul_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_proj_scalar_mul,
+            ),
+        ),
+        (
+            "g2_multi_scalar_mul_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_multi_scalar_mul,
+            ),
+        ),
+        (
+            "g2_affine_subgroup_check_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_affine_subgroup_check,
+            ),
+        ),
+        (
+            "g2_affine_serialize_compressed_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_affine_serialize_compressed,
+            ),
+        ),
+        (
+            "g2_affine_deserialize_compressed_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_affine_deserialize_compressed,
+            ),
+        ),
+        (
+            "g2_affine_hash_to_curve_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                bls12381_algebra_point::native_g2_affine_hash_to_curve,
+            ),
+        ),
+        (
+            "pairing_product_internal",
+            make_safe_native(
+                gas_params,
+                timed_features,
+                features,
+                bls12381_algebra_pairing::native_pairing_product,
+            ),
+        ),
+    ]);
+
+    crate::natives::helpers::make_module_natives(natives)
+}
@@ -0,0 +1,101 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    natives::{
+        cryptography::bls12381_algebra::{
+            GasParameters, G1_COMPRESSED_NUM_BYTES, G2_COMPRESSED_NUM_BYTES,
+        },
+        helpers::{SafeNativeContext, SafeNativeResult},
+    },
+    safely_pop_arg,
+};
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// Computes the product `e(g1s[0], g2s[0]) * ... * e(g1s[n-1], g2s[n-1])` and checks whether it
+/// equals the identity element of the target group, i.e. whether the multi-pairing vanishes.
+/// This is the check underlying pairing-based signature verification and most SNARK verifiers,
+/// and is far cheaper than computing `n` independent pairings and comparing target-group elements.
+pub fn native_pairing_product(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    // Only pop the raw, still-compressed bytes here: checking the lengths line up and charging
+    // gas must happen before any real elliptic-curve work (the `uncompress` calls below), so that
+    // a mismatched-length or over-long input is rejected at a cost proportional to its length
+    // rather than after paying for decompression first.
+    let g2s_raw = pop_raw_points::<G2_COMPRESSED_NUM_BYTES>(&mut arguments)?;
+    let g1s_raw = pop_raw_points::<G1_COMPRESSED_NUM_BYTES>(&mut arguments)?;
+
+    if g1s_raw.len() != g2s_raw.len() {
+        return Err(crate::natives::helpers::SafeNativeError::InvariantViolation(
+            move_binary_format::errors::PartialVMError::new(
+                move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+            ),
+        ));
+    }
+
+    context.charge(gas_params.pairing_product_gas(g1s_raw.len()))?;
+
+    let g1s = decode_g1_vec(g1s_raw)?;
+    let g2s = decode_g2_vec(g2s_raw)?;
+
+    let mut acc = blst::Pairing::new(true, &[]);
+    for (p, q) in g1s.iter().zip(g2s.iter()) {
+        acc.raw_aggregate(q, p);
+    }
+    acc.commit();
+
+    Ok(smallvec![Value::bool(acc.finalverify(None))])
+}
+
+/// Pops a `vector<vector<u8>>` argument and checks that every entry is exactly `N` bytes, without
+/// yet interpreting those bytes as a point. This is the only work done before gas is charged, so
+/// it must stay to cheap length checks -- no elliptic-curve arithmetic.
+fn pop_raw_points<const N: usize>(
+    arguments: &mut VecDeque<Value>,
+) -> SafeNativeResult<Vec<[u8; N]>> {
+    let raw = safely_pop_arg!(arguments, Vec<Vec<u8>>);
+    raw.into_iter()
+        .map(|bytes| {
+            <[u8; N]>::try_from(bytes).map_err(|_| {
+                crate::natives::helpers::SafeNativeError::InvariantViolation(
+                    move_binary_format::errors::PartialVMError::new(
+                        move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Decodes already-charged-for, fixed-size byte arrays into compressed affine points, rejecting
+/// the whole call if any entry is malformed. Pairing inputs come from on-chain signatures/proofs
+/// that are expected to already be subgroup-checked by the caller, so -- unlike the single-point
+/// deserialize natives -- a bad encoding here is treated as an invariant violation rather than a
+/// recoverable `(false, _)` result.
+macro_rules! decode_points {
+    ($name:ident, $affine:ty, $n:expr) => {
+        fn $name(raw: Vec<[u8; $n]>) -> SafeNativeResult<Vec<$affine>> {
+            raw.into_iter()
+                .map(|arr| {
+                    <$affine>::uncompress(&arr).map_err(|_| {
+                        crate::natives::helpers::SafeNativeError::InvariantViolation(
+                            move_binary_format::errors::PartialVMError::new(
+                                move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+                            ),
+                        )
+                    })
+                })
+                .collect()
+        }
+    };
+}
+
+decode_points!(decode_g1_vec, blst::P1Affine, G1_COMPRESSED_NUM_BYTES);
+decode_points!(decode_g2_vec, blst::P2Affine, G2_COMPRESSED_NUM_BYTES);
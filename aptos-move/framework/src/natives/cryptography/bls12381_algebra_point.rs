@@ -0,0 +1,336 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    natives::{
+        cryptography::bls12381_algebra::{
+            GasParameters, FR_NUM_BYTES, G1_COMPRESSED_NUM_BYTES, G2_COMPRESSED_NUM_BYTES,
+        },
+        helpers::{SafeNativeContext, SafeNativeResult},
+    },
+    safely_pop_arg,
+};
+use move_core_types::gas_algebra::{NumArgs, NumBytes};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Value, Vector},
+};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// Domain separation tag used for the default (IETF) hash-to-curve suite on G1.
+const G1_HASH_TO_CURVE_DST: &[u8] = b"BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// Domain separation tag used for the default (IETF) hash-to-curve suite on G2.
+const G2_HASH_TO_CURVE_DST: &[u8] = b"BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+/// Pops a fixed-size byte array off the argument stack, erroring out on size mismatch.
+fn pop_fixed_bytes<const N: usize>(arguments: &mut VecDeque<Value>) -> SafeNativeResult<[u8; N]> {
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    <[u8; N]>::try_from(bytes).map_err(|_| {
+        crate::natives::helpers::SafeNativeError::InvariantViolation(
+            move_binary_format::errors::PartialVMError::new(
+                move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+            ),
+        )
+    })
+}
+
+fn pop_vec_of_fixed_bytes<const N: usize>(
+    arguments: &mut VecDeque<Value>,
+) -> SafeNativeResult<Vec<[u8; N]>> {
+    let raw = safely_pop_arg!(arguments, Vector).to_vec_u8()?;
+
+    raw.into_iter()
+        .map(|bytes| {
+            <[u8; N]>::try_from(bytes).map_err(|_| {
+                crate::natives::helpers::SafeNativeError::InvariantViolation(
+                    move_binary_format::errors::PartialVMError::new(
+                        move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Deserializes a compressed G1 point, WITHOUT checking that it lies in the prime-order subgroup.
+/// Callers that need a subgroup-checked point should also invoke `native_g1_affine_subgroup_check`.
+fn g1_affine_from_compressed(bytes: &[u8; G1_COMPRESSED_NUM_BYTES]) -> Option<blst::P1Affine> {
+    blst::P1Affine::uncompress(bytes).ok()
+}
+
+fn g2_affine_from_compressed(bytes: &[u8; G2_COMPRESSED_NUM_BYTES]) -> Option<blst::P2Affine> {
+    blst::P2Affine::uncompress(bytes).ok()
+}
+
+/// The error returned when a Move caller passes bytes that don't decompress to a well-formed
+/// point. Since points here are bare `vector<u8>`, not an opaquely-constructed Move struct, any
+/// caller can hand the arithmetic natives attacker-controlled bytes -- so a malformed encoding
+/// must abort the transaction via a recoverable VM error, not panic the validator.
+fn malformed_point_error() -> crate::natives::helpers::SafeNativeError {
+    crate::natives::helpers::SafeNativeError::InvariantViolation(
+        move_binary_format::errors::PartialVMError::new(
+            move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+        ),
+    )
+}
+
+macro_rules! impl_group_natives {
+    (
+        $group:ident,
+        $affine:ty,
+        $proj:ty,
+        $affines_batch:ty,
+        $compressed_bytes:expr,
+        $dst:expr,
+        $from_compressed:ident,
+        $native_add:ident,
+        $native_neg:ident,
+        $native_scalar_mul:ident,
+        $native_msm:ident,
+        $native_subgroup_check:ident,
+        $native_serialize:ident,
+        $native_deserialize:ident,
+        $native_hash_to_curve:ident,
+        $gas_add:ident,
+        $gas_neg:ident,
+        $gas_scalar_mul:ident,
+        $gas_to_affine:ident,
+        $gas_subgroup_check:ident,
+        $gas_serialize:ident,
+        $gas_deserialize:ident,
+        $gas_hash_to_curve:ident,
+        $gas_hash_to_curve_per_byte:ident,
+        $msm_gas_fn:ident
+    ) => {
+        pub fn $native_add(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            context.charge(gas_params.$gas_add * NumArgs::one())?;
+
+            let b = pop_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+            let a = pop_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+
+            let pa = $from_compressed(&a).ok_or_else(malformed_point_error)?;
+            let pb = $from_compressed(&b).ok_or_else(malformed_point_error)?;
+
+            let mut sum = <$proj>::from(&pa);
+            sum.add_or_double_affine(&pb);
+
+            context.charge(gas_params.$gas_to_affine * NumArgs::one())?;
+            Ok(smallvec![Value::vector_u8(sum.to_affine().compress().to_vec())])
+        }
+
+        pub fn $native_neg(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            context.charge(gas_params.$gas_neg * NumArgs::one())?;
+
+            let a = pop_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+            let pa = $from_compressed(&a).ok_or_else(malformed_point_error)?;
+
+            let mut p = <$proj>::from(&pa);
+            p.cneg(true);
+
+            context.charge(gas_params.$gas_to_affine * NumArgs::one())?;
+            Ok(smallvec![Value::vector_u8(p.to_affine().compress().to_vec())])
+        }
+
+        pub fn $native_scalar_mul(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            context.charge(gas_params.$gas_scalar_mul * NumArgs::one())?;
+
+            let scalar = pop_fixed_bytes::<FR_NUM_BYTES>(&mut arguments)?;
+            let a = pop_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+
+            let pa = $from_compressed(&a).ok_or_else(malformed_point_error)?;
+            let product = <$proj>::from(&pa).mult(&scalar, 255);
+
+            context.charge(gas_params.$gas_to_affine * NumArgs::one())?;
+            Ok(smallvec![Value::vector_u8(
+                product.to_affine().compress().to_vec()
+            )])
+        }
+
+        /// Computes `sum_i scalars[i] * points[i]` (a multi-scalar multiplication, a.k.a. mulexp)
+        /// using `blst`'s Pippenger-backed batch multiplication.
+        pub fn $native_msm(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            let scalars = pop_vec_of_fixed_bytes::<FR_NUM_BYTES>(&mut arguments)?;
+            let points = pop_vec_of_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+
+            if scalars.len() != points.len() {
+                return Err(crate::natives::helpers::SafeNativeError::InvariantViolation(
+                    move_binary_format::errors::PartialVMError::new(
+                        move_core_types::vm_status::StatusCode::INTERNAL_TYPE_ERROR,
+                    ),
+                ));
+            }
+
+            context.charge(gas_params.$msm_gas_fn(points.len()))?;
+
+            let affines: Vec<$affine> = points
+                .iter()
+                .map(|bytes| $from_compressed(bytes).ok_or_else(malformed_point_error))
+                .collect::<SafeNativeResult<Vec<$affine>>>()?;
+            let flat_scalars: Vec<u8> = scalars.iter().flatten().copied().collect();
+
+            let result = <$affines_batch>::from(&affines).mult(&flat_scalars, 255);
+
+            context.charge(gas_params.$gas_to_affine * NumArgs::one())?;
+            Ok(smallvec![Value::vector_u8(
+                <$proj>::from(result).to_affine().compress().to_vec()
+            )])
+        }
+
+        /// Checks that a deserialized affine point lies in the prime-order subgroup. `blst`
+        /// already rejects non-canonical / off-curve encodings at `uncompress` time, so this is
+        /// the remaining check needed before the point can be trusted in pairing-based protocols.
+        pub fn $native_subgroup_check(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            context.charge(gas_params.$gas_subgroup_check * NumArgs::one())?;
+
+            let a = pop_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+            let in_subgroup = match $from_compressed(&a) {
+                Some(p) => p.in_group(),
+                None => false,
+            };
+
+            Ok(smallvec![Value::bool(in_subgroup)])
+        }
+
+        pub fn $native_serialize(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            context.charge(gas_params.$gas_serialize * NumArgs::one())?;
+
+            let a = pop_fixed_bytes::<$compressed_bytes>(&mut arguments)?;
+            // Already in compressed form; this native exists so Move call sites don't need to
+            // know the wire format, and so future point representations stay backwards compatible.
+            Ok(smallvec![Value::vector_u8(a.to_vec())])
+        }
+
+        /// Deserializes `bytes` as a compressed point. Returns `(is_valid, point)`, where
+        /// `point` is meaningless when `is_valid` is `false` -- malformed, attacker-supplied
+        /// bytes must never abort the transaction.
+        pub fn $native_deserialize(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 2]>> {
+            context.charge(gas_params.$gas_deserialize * NumArgs::one())?;
+
+            let bytes = safely_pop_arg!(arguments, Vec<u8>);
+            let is_valid = bytes.len() == $compressed_bytes
+                && <[u8; $compressed_bytes]>::try_from(bytes.as_slice())
+                    .ok()
+                    .and_then(|b| $from_compressed(&b))
+                    .is_some();
+
+            Ok(smallvec![Value::bool(is_valid), Value::vector_u8(bytes)])
+        }
+
+        /// Hashes `msg` to a uniformly-random point using the suite in `$dst`, per the
+        /// hash-to-curve construction standardized in RFC 9380.
+        pub fn $native_hash_to_curve(
+            gas_params: &GasParameters,
+            context: &mut SafeNativeContext,
+            _ty_args: Vec<Type>,
+            mut arguments: VecDeque<Value>,
+        ) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+            let msg = safely_pop_arg!(arguments, Vec<u8>);
+
+            context.charge(
+                gas_params.$gas_hash_to_curve * NumArgs::one()
+                    + gas_params.$gas_hash_to_curve_per_byte * NumBytes::new(msg.len() as u64),
+            )?;
+
+            let point = <$proj>::hash_to(&msg, $dst, &[]);
+
+            context.charge(gas_params.$gas_to_affine * NumArgs::one())?;
+            Ok(smallvec![Value::vector_u8(
+                point.to_affine().compress().to_vec()
+            )])
+        }
+    };
+}
+
+impl_group_natives!(
+    g1,
+    blst::P1Affine,
+    blst::P1,
+    blst::p1_affines,
+    G1_COMPRESSED_NUM_BYTES,
+    G1_HASH_TO_CURVE_DST,
+    g1_affine_from_compressed,
+    native_g1_proj_add,
+    native_g1_proj_neg,
+    native_g1_proj_scalar_mul,
+    native_g1_multi_scalar_mul,
+    native_g1_affine_subgroup_check,
+    native_g1_affine_serialize_compressed,
+    native_g1_affine_deserialize_compressed,
+    native_g1_affine_hash_to_curve,
+    g1_proj_add,
+    g1_proj_neg,
+    g1_proj_scalar_mul,
+    g1_proj_to_affine,
+    g1_affine_subgroup_check,
+    g1_affine_serialize_compressed,
+    g1_affine_deserialize_compressed,
+    g1_affine_hash_to_curve,
+    g1_affine_hash_to_curve_per_byte,
+    g1_multi_scalar_mul_gas
+);
+
+impl_group_natives!(
+    g2,
+    blst::P2Affine,
+    blst::P2,
+    blst::p2_affines,
+    G2_COMPRESSED_NUM_BYTES,
+    G2_HASH_TO_CURVE_DST,
+    g2_affine_from_compressed,
+    native_g2_proj_add,
+    native_g2_proj_neg,
+    native_g2_proj_scalar_mul,
+    native_g2_multi_scalar_mul,
+    native_g2_affine_subgroup_check,
+    native_g2_affine_serialize_compressed,
+    native_g2_affine_deserialize_compressed,
+    native_g2_affine_hash_to_curve,
+    g2_proj_add,
+    g2_proj_neg,
+    g2_proj_scalar_mul,
+    g2_proj_to_affine,
+    g2_affine_subgroup_check,
+    g2_affine_serialize_compressed,
+    g2_affine_deserialize_compressed,
+    g2_affine_hash_to_curve,
+    g2_affine_hash_to_curve_per_byte,
+    g2_multi_scalar_mul_gas
+);
@@ -0,0 +1,7 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod bls12381_algebra;
+pub mod bls12381_algebra_pairing;
+pub mod bls12381_algebra_point;
+pub mod ristretto255;
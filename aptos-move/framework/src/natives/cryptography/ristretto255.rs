@@ -6,7 +6,7 @@ use crate::natives::helpers::make_test_only_safe_native;
 use crate::{
     natives::{
         cryptography::{ristretto255_point, ristretto255_scalar},
-        helpers::{make_safe_native, SafeNativeError, SafeNativeResult},
+        helpers::{make_safe_native, SafeNativeContext, SafeNativeError, SafeNativeResult},
     },
     safely_assert_eq, safely_pop_arg,
 };
@@ -14,14 +14,20 @@ use aptos_types::{
     on_chain_config::{Features, TimedFeatures},
     vm_status::StatusCode,
 };
-use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
 use move_binary_format::errors::PartialVMError;
 use move_core_types::gas_algebra::{
     GasQuantity, InternalGasPerArg, InternalGasPerByte, InternalGasUnit, NumArgs,
 };
 use move_vm_runtime::native_functions::NativeFunction;
-use move_vm_types::values::{Reference, StructRef, Value};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Reference, StructRef, Value},
+};
+use sha2::{Digest, Sha512};
+use smallvec::{smallvec, SmallVec};
 use std::{collections::VecDeque, sync::Arc};
+use subtle::ConstantTimeEq;
 
 /// The size of a serialized scalar, in bytes.
 pub(crate) const SCALAR_NUM_BYTES: usize = 32;
@@ -39,6 +45,7 @@ pub struct GasParameters {
     pub point_compress: InternalGasPerArg,
     pub point_decompress: InternalGasPerArg,
     pub point_equals: InternalGasPerArg,
+    pub point_equals_ct: InternalGasPerArg,
     pub point_from_64_uniform_bytes: InternalGasPerArg,
     pub point_identity: InternalGasPerArg,
     pub point_mul: InternalGasPerArg,
@@ -57,6 +64,7 @@ pub struct GasParameters {
     pub scalar_uniform_from_64_bytes: InternalGasPerArg,
     pub scalar_from_u128: InternalGasPerArg,
     pub scalar_from_u64: InternalGasPerArg,
+    pub scalar_equals_ct: InternalGasPerArg,
     pub scalar_invert: InternalGasPerArg,
     pub scalar_is_canonical: InternalGasPerArg,
     pub scalar_mul: InternalGasPerArg,
@@ -166,6 +174,15 @@ pub fn make_all(
                 ristretto255_point::native_point_equals,
             ),
         ),
+        (
+            "point_equals_ct_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                native_point_equals_ct,
+            ),
+        ),
         (
             "point_neg_internal",
             make_safe_native(
@@ -341,11 +358,56 @@ pub fn make_all(
         ),
         (
             "scalar_uniform_from_64_bytes_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                ristretto255_scalar::native_scalar_uniform_from_64_bytes,
+            ),
+        ),
+        (
+            "hash_to_point_with_dst_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                native_hash_to_point_with_dst,
+            ),
+        ),
+        (
+            "hash_to_scalar_with_dst_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                native_hash_to_scalar_with_dst,
+            ),
+        ),
+        (
+            "scalar_batch_invert_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                native_scalar_batch_invert,
+            ),
+        ),
+        (
+            "scalar_from_canonical_bytes_internal",
+            make_safe_native(
+                gas_params.clone(),
+                timed_features.clone(),
+                features.clone(),
+                native_scalar_from_canonical_bytes,
+            ),
+        ),
+        (
+            "scalar_equals_ct_internal",
             make_safe_native(
                 gas_params,
                 timed_features,
                 features,
-                ristretto255_scalar::native_scalar_uniform_from_64_bytes,
+                native_scalar_equals_ct,
             ),
         ),
     ]);
@@ -410,3 +472,251 @@ pub fn scalar_from_valid_bytes(bytes: Vec<u8>) -> SafeNativeResult<Scalar> {
 
     Ok(s)
 }
+
+/// SHA-512's internal block size, in bytes. Used as the length of the `Z_pad` prefix in
+/// `expand_message_xmd`, per RFC 9380, section 5.3.1.
+const SHA512_BLOCK_NUM_BYTES: usize = 128;
+
+/// The maximum length of a domain-separation tag accepted by `expand_message_xmd`, per RFC 9380,
+/// section 5.3.3 (so that `I2OSP(len(DST), 1)` fits in a single byte).
+const MAX_DST_NUM_BYTES: usize = 255;
+
+/// Implements `expand_message_xmd` from RFC 9380, section 5.3.1, specialized to SHA-512 and to
+/// producing exactly 64 bytes of uniformly random output (i.e. `len_in_bytes = 64`, which is
+/// exactly one SHA-512 output, so only `b_0` and `b_1` are needed).
+///
+/// Charges `sha512_per_hash` for each of the two hash calls, plus `sha512_per_byte` for the
+/// `Z_pad || msg || ...` block hashed to produce `b_0`.
+fn expand_message_xmd_sha512_64(
+    context: &mut SafeNativeContext,
+    gas_params: &GasParameters,
+    msg: &[u8],
+    dst: &[u8],
+) -> SafeNativeResult<[u8; 64]> {
+    if dst.len() > MAX_DST_NUM_BYTES {
+        return Err(SafeNativeError::InvariantViolation(PartialVMError::new(
+            StatusCode::INVALID_DATA,
+        )));
+    }
+
+    let mut dst_prime = Vec::with_capacity(dst.len() + 1);
+    dst_prime.extend_from_slice(dst);
+    dst_prime.push(dst.len() as u8);
+
+    let z_pad = [0u8; SHA512_BLOCK_NUM_BYTES];
+
+    let mut b_0_input = Vec::with_capacity(z_pad.len() + msg.len() + 2 + 1 + dst_prime.len());
+    b_0_input.extend_from_slice(&z_pad);
+    b_0_input.extend_from_slice(msg);
+    b_0_input.extend_from_slice(&64u16.to_be_bytes()); // I2OSP(len_in_bytes, 2)
+    b_0_input.push(0); // I2OSP(0, 1)
+    b_0_input.extend_from_slice(&dst_prime);
+
+    context.charge(
+        gas_params.sha512_per_hash * NumArgs::one()
+            + gas_params.sha512_per_byte
+                * move_core_types::gas_algebra::NumBytes::new(b_0_input.len() as u64),
+    )?;
+    let b_0 = Sha512::digest(&b_0_input);
+
+    let mut b_1_input = Vec::with_capacity(b_0.len() + 1 + dst_prime.len());
+    b_1_input.extend_from_slice(&b_0);
+    b_1_input.push(1); // I2OSP(1, 1)
+    b_1_input.extend_from_slice(&dst_prime);
+
+    context.charge(gas_params.sha512_per_hash * NumArgs::one())?;
+    let b_1 = Sha512::digest(&b_1_input);
+
+    Ok(b_1.into())
+}
+
+/// An RFC 9380-compliant, domain-separated hash-to-group native: hashes `(msg, dst)` to a
+/// uniformly random point on the Ristretto255 group, via `expand_message_xmd` followed by the
+/// same one-way map used by `new_point_from_64_uniform_bytes_internal`.
+///
+/// Unlike `new_point_from_sha512_internal`, which hashes exactly 64 bytes with no domain
+/// separation and is therefore unsafe to reuse across protocols (e.g. for a VOPRF), this native
+/// lets every caller mix in its own `dst` so that outputs cannot collide across protocols.
+pub fn native_hash_to_point_with_dst(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let dst = safely_pop_arg!(arguments, Vec<u8>);
+    let msg = safely_pop_arg!(arguments, Vec<u8>);
+
+    let uniform_bytes = expand_message_xmd_sha512_64(context, gas_params, &msg, &dst)?;
+
+    context.charge(gas_params.point_from_64_uniform_bytes * NumArgs::one())?;
+    let point = RistrettoPoint::from_uniform_bytes(&uniform_bytes);
+
+    Ok(smallvec![Value::vector_u8(
+        point.compress().to_bytes().to_vec()
+    )])
+}
+
+/// The scalar counterpart to [`native_hash_to_point_with_dst`]: hashes `(msg, dst)` to a
+/// uniformly random `Scalar` via `expand_message_xmd` followed by wide reduction mod `\ell`,
+/// the same reduction used by `scalar_uniform_from_64_bytes_internal`.
+pub fn native_hash_to_scalar_with_dst(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let dst = safely_pop_arg!(arguments, Vec<u8>);
+    let msg = safely_pop_arg!(arguments, Vec<u8>);
+
+    let uniform_bytes = expand_message_xmd_sha512_64(context, gas_params, &msg, &dst)?;
+
+    context.charge(gas_params.scalar_uniform_from_64_bytes * NumArgs::one())?;
+    let scalar = Scalar::from_bytes_mod_order_wide(&uniform_bytes);
+
+    Ok(smallvec![Value::vector_u8(scalar.to_bytes().to_vec())])
+}
+
+/// Inverts a batch of scalars using Montgomery's trick, which computes `n` inversions via a
+/// single field inversion (plus `O(n)` multiplications) rather than `n` separate inversions.
+///
+/// Rejects the whole call if any input scalar is zero, since zero has no multiplicative inverse.
+pub fn native_scalar_batch_invert(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    let scalar_bytes = safely_pop_arg!(arguments, Vec<Vec<u8>>);
+
+    context.charge(
+        gas_params.scalar_invert * NumArgs::one()
+            + gas_params.scalar_mul * NumArgs::new(3 * scalar_bytes.len() as u64),
+    )?;
+
+    let scalars = scalar_bytes
+        .into_iter()
+        .map(scalar_from_valid_bytes)
+        .collect::<SafeNativeResult<Vec<Scalar>>>()?;
+
+    if scalars.iter().any(|s| *s == Scalar::zero()) {
+        return Err(SafeNativeError::InvariantViolation(PartialVMError::new(
+            StatusCode::INVALID_DATA,
+        )));
+    }
+
+    // Forward sweep: prefix_products[i] = a_0 * a_1 * ... * a_i.
+    let mut prefix_products = Vec::with_capacity(scalars.len());
+    let mut running_product = Scalar::one();
+    for s in &scalars {
+        running_product *= s;
+        prefix_products.push(running_product);
+    }
+
+    // A single inversion of the full product, instead of one per scalar.
+    let mut acc = running_product.invert();
+
+    // Backward sweep: inv(a_i) = prefix_products[i - 1] * acc, then fold a_i into acc.
+    let mut inverses = vec![Scalar::one(); scalars.len()];
+    for i in (0..scalars.len()).rev() {
+        let prefix = if i == 0 {
+            Scalar::one()
+        } else {
+            prefix_products[i - 1]
+        };
+        inverses[i] = prefix * acc;
+        acc *= scalars[i];
+    }
+
+    // Returned as a single flat buffer of `n` concatenated 32-byte scalars, rather than a Move
+    // `vector<vector<u8>>`: `Value` has production constructors for vectors of primitives
+    // (`vector_u8`, `vector_bool`, ...) but not for a generic `vector<T>` of struct-shaped
+    // elements, so the Move-level wrapper `scalar_batch_invert` splits this back into `n` 32-byte
+    // chunks, returned as a `vector<vector<u8>>`.
+    let mut flat_bytes = Vec::with_capacity(inverses.len() * SCALAR_NUM_BYTES);
+    for s in inverses {
+        flat_bytes.extend_from_slice(s.as_bytes());
+    }
+
+    Ok(smallvec![Value::vector_u8(flat_bytes)])
+}
+
+/// Strictly decodes a canonical, little-endian-encoded scalar, mirroring curve25519-dalek's
+/// `Scalar::from_canonical_bytes`. Unlike `scalar_from_valid_bytes` (used by
+/// `scalar_is_canonical_internal` and friends), which calls `Scalar::from_bits` -- silently
+/// clearing the high bit of a malformed encoding -- this returns `(false, _)` on any
+/// non-canonical input (including values `>= \ell`) instead of silently mangling it or tripping
+/// an invariant-violation abort. This lets Move callers treat attacker-supplied bytes (e.g. in a
+/// FROST `deserialize` routine) as a recoverable `(false, _)` result, not a transaction abort.
+pub fn native_scalar_from_canonical_bytes(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 2]>> {
+    let bytes = safely_pop_arg!(arguments, Vec<u8>);
+
+    context.charge(gas_params.scalar_is_canonical * NumArgs::one())?;
+
+    let scalar = <[u8; 32]>::try_from(bytes.as_slice())
+        .ok()
+        .and_then(Scalar::from_canonical_bytes);
+
+    match scalar {
+        Some(s) => Ok(smallvec![
+            Value::bool(true),
+            Value::vector_u8(s.to_bytes().to_vec())
+        ]),
+        None => Ok(smallvec![
+            Value::bool(false),
+            Value::vector_u8(vec![0u8; SCALAR_NUM_BYTES])
+        ]),
+    }
+}
+
+/// A constant-time counterpart to `point_equals`. Callers comparing a secret-dependent
+/// point (e.g. an OPRF evaluation result) against a public one should use this instead, since an
+/// early-exit comparison can leak which byte the two points first differ at through timing.
+///
+/// Always folds the full 32-byte comparison before returning, and charges a flat, input-
+/// independent gas cost so the charge itself cannot be used to infer where a mismatch occurred.
+///
+/// Unlike `native_point_equals`, this never decompresses `a`/`b`: a canonical Ristretto255
+/// encoding is a bijection, so comparing the raw 32-byte encodings directly is equivalent to
+/// comparing the points, without risking a panic on a caller-supplied, length-valid-but-malformed
+/// (non-canonical or off-curve) byte string -- `a`/`b` are raw Move bytes with no prior validation
+/// that they decompress to a point.
+pub fn native_point_equals_ct(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    context.charge(gas_params.point_equals_ct * NumArgs::one())?;
+
+    let b = pop_32_byte_slice(&mut arguments)?;
+    let a = pop_32_byte_slice(&mut arguments)?;
+
+    let equals: bool = a.ct_eq(&b).into();
+
+    Ok(smallvec![Value::bool(equals)])
+}
+
+/// A constant-time counterpart to Move-level scalar equality. Scalar equality in Move is usually
+/// just field-by-field struct equality, which is not guaranteed to be constant-time; this native
+/// gives contracts doing secret-dependent scalar comparisons (e.g. a committed blinding factor) a
+/// side-channel-resistant option, using curve25519-dalek's `subtle`-based `ConstantTimeEq`.
+pub fn native_scalar_equals_ct(
+    gas_params: &GasParameters,
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    context.charge(gas_params.scalar_equals_ct * NumArgs::one())?;
+
+    let b = pop_scalar_from_bytes(&mut arguments)?;
+    let a = pop_scalar_from_bytes(&mut arguments)?;
+
+    let equals: bool = a.as_bytes().ct_eq(b.as_bytes()).into();
+
+    Ok(smallvec![Value::bool(equals)])
+}
@@ -0,0 +1,60 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod cryptography;
+
+use crate::natives::cryptography::{bls12381_algebra, ristretto255};
+use aptos_types::on_chain_config::{Features, TimedFeatures};
+use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_vm_runtime::native_functions::{NativeFunction, NativeFunctionTable};
+use std::sync::Arc;
+
+/// Gas parameters for every native module registered by [`all_natives`] below. Grouped the same
+/// way the modules themselves are grouped, so adding a new cryptography native module means
+/// adding one field here and one `natives.extend(..)` call in `all_natives`.
+#[derive(Debug, Clone)]
+pub struct CryptographyNativesGasParameters {
+    pub ristretto255: ristretto255::GasParameters,
+    pub bls12381_algebra: bls12381_algebra::GasParameters,
+}
+
+/// Assembles the native function table for every `aptos_std::cryptography` module, i.e. the
+/// `(address, module name, function name, implementation)` tuples the Move VM uses to resolve a
+/// `native fun` declaration to its Rust implementation.
+pub fn all_natives(
+    gas_params: CryptographyNativesGasParameters,
+    timed_features: TimedFeatures,
+    features: Arc<Features>,
+) -> NativeFunctionTable {
+    let mut natives = vec![];
+
+    natives.extend(natives_for_module(
+        "ristretto255",
+        ristretto255::make_all(
+            gas_params.ristretto255,
+            timed_features.clone(),
+            features.clone(),
+        ),
+    ));
+    natives.extend(natives_for_module(
+        "bls12381_algebra",
+        bls12381_algebra::make_all(gas_params.bls12381_algebra, timed_features, features),
+    ));
+
+    natives
+}
+
+/// Tags every native produced by a module's `make_all` with that module's name and the
+/// `aptos_std` address they're published under, as the Move VM's native function table expects.
+fn natives_for_module(
+    module_name: &str,
+    natives: impl Iterator<Item = (String, NativeFunction)>,
+) -> impl Iterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)> {
+    let module_name = Identifier::new(module_name.to_string())
+        .expect("native module names are hardcoded and always valid identifiers");
+    natives.map(move |(func_name, func)| {
+        let func_name = Identifier::new(func_name)
+            .expect("native function names are hardcoded and always valid identifiers");
+        (AccountAddress::ONE, module_name.clone(), func_name, func)
+    })
+}